@@ -30,7 +30,7 @@ impl Access for ast::Ident<'_> {
     fn access<'a>(self, vm: &'a mut Vm) -> SourceResult<&'a mut Value> {
         let span = self.span();
         if vm.inspected == Some(span) {
-            if let Ok(value) = vm.scopes.get(&self).cloned() {
+            if let Some(value) = vm.scopes.lookup(&self).cloned() {
                 vm.trace(value);
             }
         }
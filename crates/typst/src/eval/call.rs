@@ -1,7 +1,7 @@
 use comemo::{Tracked, TrackedMut};
 use ecow::{eco_format, EcoVec};
 
-use crate::diag::{bail, error, At, HintedStrResult, SourceResult, Trace, Tracepoint};
+use crate::diag::{bail, error, At, SourceResult, Trace, Tracepoint};
 use crate::engine::{Engine, Sink, Traced};
 use crate::eval::{Access, Eval, FlowEvent, Route, Vm};
 use crate::foundations::{
@@ -393,9 +393,9 @@ impl<'a> CapturesVisitor<'a> {
             // Identifiers that shouldn't count as captures because they
             // actually bind a new name are handled below (individually through
             // the expressions that contain them).
-            Some(ast::Expr::Ident(ident)) => self.capture(&ident, Scopes::get),
+            Some(ast::Expr::Ident(ident)) => self.capture(&ident, Scopes::lookup),
             Some(ast::Expr::MathIdent(ident)) => {
-                self.capture(&ident, Scopes::get_in_math)
+                self.capture(&ident, Scopes::lookup_in_math)
             }
 
             // Code and content blocks create a scope.
@@ -510,12 +510,12 @@ impl<'a> CapturesVisitor<'a> {
     fn capture(
         &mut self,
         ident: &str,
-        getter: impl FnOnce(&'a Scopes<'a>, &str) -> HintedStrResult<&'a Value>,
+        getter: impl FnOnce(&'a Scopes<'a>, &str) -> Option<&'a Value>,
     ) {
-        if self.internal.get(ident).is_err() {
+        if self.internal.lookup(ident).is_none() {
             let Some(value) = self
                 .external
-                .map(|external| getter(external, ident).ok())
+                .map(|external| getter(external, ident))
                 .unwrap_or(Some(&Value::None))
             else {
                 return;
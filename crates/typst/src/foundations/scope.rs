@@ -46,32 +46,47 @@ impl<'a> Scopes<'a> {
 
     /// Try to access a variable immutably.
     pub fn get(&self, var: &str) -> HintedStrResult<&Value> {
-        std::iter::once(&self.top)
-            .chain(self.scopes.iter().rev())
-            .find_map(|scope| scope.get(var))
-            .or_else(|| {
-                self.base.and_then(|base| match base.global.scope().get(var) {
-                    Some(value) => Some(value),
-                    None if var == "std" => Some(&base.std),
-                    None => None,
-                })
-            })
-            .ok_or_else(|| unknown_variable(var))
+        self.lookup(var)
+            .ok_or_else(|| unknown_variable(var, self.similar(var, false)))
     }
 
     /// Try to access a variable immutably in math.
     pub fn get_in_math(&self, var: &str) -> HintedStrResult<&Value> {
+        self.lookup_in_math(var)
+            .ok_or_else(|| unknown_variable(var, self.similar(var, true)))
+    }
+
+    /// Look up a variable without building an error if it doesn't exist.
+    ///
+    /// Prefer this over [`get`](Self::get) when a missing variable is not an
+    /// error, as the error of the latter suggests similar names, which is
+    /// expensive.
+    pub fn lookup(&self, var: &str) -> Option<&Value> {
+        self.lookup_in(var, |base| base.global.scope())
+    }
+
+    /// Look up a variable in math without building an error if it doesn't
+    /// exist.
+    pub fn lookup_in_math(&self, var: &str) -> Option<&Value> {
+        self.lookup_in(var, |base| base.math.scope())
+    }
+
+    /// Look up a variable, falling back to the given scope of the library.
+    fn lookup_in(
+        &self,
+        var: &str,
+        library_scope: impl FnOnce(&'a Library) -> &'a Scope,
+    ) -> Option<&Value> {
         std::iter::once(&self.top)
             .chain(self.scopes.iter().rev())
             .find_map(|scope| scope.get(var))
             .or_else(|| {
-                self.base.and_then(|base| match base.math.scope().get(var) {
+                self.base.and_then(|base| match library_scope(base).get(var) {
                     Some(value) => Some(value),
                     None if var == "std" => Some(&base.std),
                     None => None,
                 })
             })
-            .ok_or_else(|| unknown_variable(var))
     }
 
     /// Try to access a variable mutably.
@@ -83,10 +98,31 @@ impl<'a> Scopes<'a> {
                 match self.base.and_then(|base| base.global.scope().get(var)) {
                     Some(_) => cannot_mutate_constant(var),
                     _ if var == "std" => cannot_mutate_constant(var),
-                    _ => unknown_variable(var),
+                    // Unlike for reads, no similar name is suggested: the
+                    // scopes are still borrowed mutably here, so that would
+                    // require a second lookup on every assignment.
+                    _ => unknown_variable(var, None),
                 }
             })?
     }
+
+    /// Find the variable whose name is closest to `var`, if any is close
+    /// enough to suggest it in case of a typo.
+    #[cold]
+    fn similar(&self, var: &str, math: bool) -> Option<&str> {
+        let base =
+            self.base
+                .map(|base| if math { base.math.scope() } else { base.global.scope() });
+        let max = var.chars().count() / 3;
+        std::iter::once(&self.top)
+            .chain(self.scopes.iter().rev())
+            .chain(base)
+            .flat_map(|scope| scope.iter())
+            .map(|(name, _)| (edit_distance(var, name), name.as_str()))
+            .filter(|&(distance, _)| distance <= max)
+            .min()
+            .map(|(_, name)| name)
+    }
 }
 
 #[cold]
@@ -94,9 +130,10 @@ fn cannot_mutate_constant(var: &str) -> HintedString {
     eco_format!("cannot mutate a constant: {}", var).into()
 }
 
-/// The error message when a variable is not found.
+/// The error message when a variable is not found, optionally suggesting a
+/// similarly named one.
 #[cold]
-fn unknown_variable(var: &str) -> HintedString {
+fn unknown_variable(var: &str, similar: Option<&str>) -> HintedString {
     let mut res = HintedString::new(eco_format!("unknown variable: {}", var));
 
     if matches!(var, "none" | "auto" | "false" | "true") {
@@ -107,11 +144,31 @@ fn unknown_variable(var: &str) -> HintedString {
         res.hint(eco_format!(
             "if you meant to use subtraction, try adding spaces around the minus sign",
         ));
+    } else if let Some(similar) = similar {
+        res.hint(eco_format!("did you mean `{similar}`?"));
     }
 
     res
 }
 
+/// The number of single-character insertions, deletions, and substitutions
+/// needed to turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, x) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &y) in b.iter().enumerate() {
+            let next =
+                (diagonal + usize::from(x != y)).min(row[j] + 1).min(row[j + 1] + 1);
+            diagonal = row[j + 1];
+            row[j + 1] = next;
+        }
+    }
+    row[b.len()]
+}
+
 /// A map from binding names to values.
 #[derive(Default, Clone)]
 pub struct Scope {
@@ -14,7 +14,7 @@ use crate::layout::{
 };
 use crate::model::{Destination, LinkElem};
 use crate::syntax::Span;
-use crate::text::TextItem;
+use crate::text::{Glyph, TextItem};
 use crate::utils::{LazyHash, Numeric};
 use crate::visualize::{
     ellipse, styled_rect, Color, FixedStroke, Geometry, Image, Paint, Path, Shape,
@@ -307,6 +307,63 @@ impl Frame {
         }
     }
 
+    /// Merge directly consecutive text items that continue each other
+    /// seamlessly.
+    ///
+    /// Text is often split into multiple items at style boundaries that don't
+    /// affect its appearance. Merging them keeps the output of exporters lean.
+    /// Two items are only merged if they share all their properties, sit on
+    /// the same baseline, and the second one starts exactly where the first
+    /// one ends. Moreover, `ltr` must hold `true` for both of them, since the
+    /// visual and logical order of right-to-left text disagree. It holds one
+    /// entry per item of this frame.
+    ///
+    /// Only items directly in this frame are considered. Text in frames that
+    /// were pushed as groups, i.e. that [`push_frame`](Self::push_frame)
+    /// didn't inline, is never merged.
+    pub(crate) fn coalesce_text(&mut self, ltr: &[bool]) {
+        debug_assert_eq!(ltr.len(), self.items.len());
+
+        // The item at `prev` is the one that `next` would be appended to.
+        // While compacting, it may already hold the items up to `next - 1`
+        // and those are only grouped if all of them are left-to-right, so
+        // checking the item right before `next` suffices.
+        let mergeable = |items: &[(Point, FrameItem)], prev: usize, next: usize| {
+            ltr[next - 1] && ltr[next] && continues_text(&items[prev], &items[next])
+        };
+
+        // Most lines have nothing to merge, so we avoid copying the items
+        // unless necessary.
+        if !(1..self.items.len()).any(|i| mergeable(&self.items, i - 1, i)) {
+            return;
+        }
+
+        // Compact the items in place. The first `len` items are the ones that
+        // are kept, with merged items appended to the last of them.
+        let items = Arc::make_mut(&mut self.items);
+        let mut len = 1;
+        for i in 1..items.len() {
+            if mergeable(items, len - 1, i) {
+                let (kept, rest) = items.split_at_mut(i);
+                if let ((_, FrameItem::Text(prev)), (_, FrameItem::Text(next))) =
+                    (&mut kept[len - 1], &rest[0])
+                {
+                    let base = prev.text.len() as u16;
+                    prev.text.push_str(&next.text);
+                    prev.glyphs.extend(next.glyphs.iter().map(|glyph| Glyph {
+                        range: glyph.range.start + base..glyph.range.end + base,
+                        ..glyph.clone()
+                    }));
+                }
+            } else {
+                items.swap(len, i);
+                len += 1;
+            }
+        }
+
+        items.truncate(len);
+    }
+
     /// Apply late-stage properties from the style chain to this frame. This
     /// includes:
     /// - `HideElem::hidden`
@@ -415,6 +472,27 @@ impl Frame {
     }
 }
 
+/// Whether the item `next` is a text item that can be appended to the text
+/// item `prev` without changing the output.
+fn continues_text(
+    (prev_pos, prev): &(Point, FrameItem),
+    (next_pos, next): &(Point, FrameItem),
+) -> bool {
+    let (FrameItem::Text(prev), FrameItem::Text(next)) = (prev, next) else {
+        return false;
+    };
+
+    prev.font == next.font
+        && prev.size == next.size
+        && prev.fill == next.fill
+        && prev.stroke == next.stroke
+        && prev.lang == next.lang
+        && prev.region == next.region
+        && next_pos.y.approx_eq(prev_pos.y)
+        && next_pos.x.approx_eq(prev_pos.x + prev.width())
+        && prev.text.len() + next.text.len() <= usize::from(u16::MAX)
+}
+
 /// Tools for debugging.
 impl Frame {
     /// Add a full size aqua background and a red baseline for debugging.
@@ -599,3 +677,72 @@ impl From<Position> for Dict {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foundations::Bytes;
+    use crate::layout::Em;
+    use crate::text::{Font, Lang};
+
+    fn text(font: &Font, text: &str) -> TextItem {
+        let glyphs = (0..text.len() as u16)
+            .map(|i| Glyph {
+                id: 0,
+                x_advance: Em::new(0.5),
+                x_offset: Em::zero(),
+                range: i..i + 1,
+                span: (Span::detached(), 0),
+            })
+            .collect();
+        TextItem {
+            font: font.clone(),
+            size: Abs::pt(10.0),
+            fill: Color::BLACK.into(),
+            stroke: None,
+            lang: Lang::ENGLISH,
+            region: None,
+            text: text.into(),
+            glyphs,
+        }
+    }
+
+    #[test]
+    fn test_coalesce_text() {
+        let data = typst_dev_assets::fonts().next().unwrap();
+        let font = Font::new(Bytes::from_static(data), 0).unwrap();
+
+        let mut frame = Frame::soft(Size::zero());
+        frame.push(Point::zero(), FrameItem::Text(text(&font, "ab")));
+        frame.push(Point::with_x(Abs::pt(10.0)), FrameItem::Text(text(&font, "cd")));
+        frame.push(Point::with_x(Abs::pt(30.0)), FrameItem::Text(text(&font, "ef")));
+        frame.coalesce_text(&[true; 3]);
+
+        let items: Vec<_> = frame.items().collect();
+        assert_eq!(items.len(), 2);
+        let FrameItem::Text(merged) = &items[0].1 else { panic!() };
+        assert_eq!(merged.text, "abcd");
+        assert_eq!(merged.glyphs[3].range, 3..4);
+        assert_eq!(items[1].0, Point::with_x(Abs::pt(30.0)));
+    }
+
+    #[test]
+    fn test_coalesce_text_keeps_rtl() {
+        let data = typst_dev_assets::fonts().next().unwrap();
+        let font = Font::new(Bytes::from_static(data), 0).unwrap();
+
+        // Single-glyph items look the same in both directions, so only the
+        // direction flags can prevent the merge.
+        let mut frame = Frame::soft(Size::zero());
+        frame.push(Point::zero(), FrameItem::Text(text(&font, "a")));
+        frame.push(Point::with_x(Abs::pt(5.0)), FrameItem::Text(text(&font, "b")));
+        frame.push(Point::with_x(Abs::pt(10.0)), FrameItem::Text(text(&font, "c")));
+        frame.coalesce_text(&[false, true, true]);
+
+        let items: Vec<_> = frame.items().collect();
+        assert_eq!(items.len(), 2);
+        let FrameItem::Text(merged) = &items[1].1 else { panic!() };
+        assert_eq!(merged.text, "bc");
+        assert_eq!(items[1].0, Point::with_x(Abs::pt(5.0)));
+    }
+}
@@ -1397,11 +1397,11 @@ fn commit(
     // Build the frames and determine the height and baseline.
     let mut frames = vec![];
     for item in reordered {
-        let mut push = |offset: &mut Abs, frame: Frame| {
+        let mut push = |offset: &mut Abs, frame: Frame, ltr: bool| {
             let width = frame.width();
             top.set_max(frame.baseline());
             bottom.set_max(frame.size().y - frame.baseline());
-            frames.push((*offset, frame));
+            frames.push((*offset, frame, ltr));
             *offset += width;
         };
 
@@ -1417,7 +1417,7 @@ fn commit(
                         elem.layout(engine, loc.relayout(), *styles, region)?;
                     frame.post_process(*styles);
                     frame.translate(Point::with_y(TextElem::baseline_in(*styles)));
-                    push(&mut offset, frame);
+                    push(&mut offset, frame, false);
                 } else {
                     offset += amount;
                 }
@@ -1426,18 +1426,18 @@ fn commit(
                 let mut frame =
                     shaped.build(engine, justification_ratio, extra_justification);
                 frame.post_process(shaped.styles);
-                push(&mut offset, frame);
+                push(&mut offset, frame, shaped.dir == Dir::LTR);
             }
             Item::Frame(frame, styles) => {
                 let mut frame = frame.clone();
                 frame.post_process(*styles);
                 frame.translate(Point::with_y(TextElem::baseline_in(*styles)));
-                push(&mut offset, frame);
+                push(&mut offset, frame, false);
             }
             Item::Tag(tag) => {
                 let mut frame = Frame::soft(Size::zero());
                 frame.push(Point::zero(), FrameItem::Tag((*tag).clone()));
-                frames.push((offset, frame));
+                frames.push((offset, frame, false));
             }
             Item::Skip(_) => {}
         }
@@ -1452,13 +1452,18 @@ fn commit(
     let mut output = Frame::soft(size);
     output.set_baseline(top);
 
-    // Construct the line's frame.
-    for (offset, frame) in frames {
+    // Construct the line's frame, remembering which of its layers hold
+    // left-to-right text so that only those are coalesced. Frames that aren't
+    // inlined become a single group layer, whose text isn't coalesced.
+    let mut ltr = vec![];
+    for (offset, frame, is_ltr) in frames {
         let x = offset + p.align.position(remaining);
         let y = top - frame.baseline();
         output.push_frame(Point::new(x, y), frame);
+        ltr.resize(output.layer(), is_ltr);
     }
 
+    output.coalesce_text(&ltr);
     Ok(output)
 }
 
@@ -248,6 +248,15 @@ pub struct TextElem {
     /// #set text(fill: red)
     /// This text is red.
     /// ```
+    ///
+    /// Gradients and patterns are also supported. On text, they are relative to
+    /// the closest parent container, as described in the
+    /// [gradient documentation]($gradient/#relativeness).
+    ///
+    /// ```example
+    /// #set text(fill: gradient.linear(red, blue))
+    /// This text fades from red to blue.
+    /// ```
     #[parse({
         let paint: Option<Spanned<Paint>> = args.named_or_find("fill")?;
         if let Some(paint) = &paint {
@@ -664,6 +673,20 @@ pub struct TextElem {
     #[ghost]
     pub fractions: bool,
 
+    /// Whether to turn the letters after numbers into ordinal indicators.
+    /// Setting this to `{true}` enables the OpenType `ordn` font feature.
+    ///
+    /// Like fractions, this should only be enabled locally, as it affects all
+    /// letters that directly follow a number.
+    ///
+    /// ```example
+    /// 1st \
+    /// #text(ordinals: true)[1st]
+    /// ```
+    #[default(false)]
+    #[ghost]
+    pub ordinals: bool,
+
     /// Raw OpenType features to apply.
     ///
     /// - If given an array of strings, sets the features identified by the
@@ -719,11 +742,10 @@ pub struct TextElem {
     #[ghost]
     pub case: Option<Case>,
 
-    /// Whether small capital glyphs should be used. ("smcp")
+    /// Whether small capital glyphs should be used. ("smcp", "c2sc")
     #[internal]
-    #[default(false)]
     #[ghost]
-    pub smallcaps: bool,
+    pub smallcaps: Option<Smallcaps>,
 }
 
 impl TextElem {
@@ -1160,8 +1182,13 @@ pub(crate) fn features(styles: StyleChain) -> Vec<Feature> {
     }
 
     // Features that are off by default in Harfbuzz are only added if enabled.
-    if TextElem::smallcaps_in(styles) {
-        feat(b"smcp", 1);
+    match TextElem::smallcaps_in(styles) {
+        Some(Smallcaps::Minuscules) => feat(b"smcp", 1),
+        Some(Smallcaps::All) => {
+            feat(b"smcp", 1);
+            feat(b"c2sc", 1);
+        }
+        None => {}
     }
 
     if TextElem::alternates_in(styles) {
@@ -1184,7 +1211,7 @@ pub(crate) fn features(styles: StyleChain) -> Vec<Feature> {
     }
 
     if TextElem::historical_ligatures_in(styles) {
-        feat(b"hilg", 1);
+        feat(b"hlig", 1);
     }
 
     match TextElem::number_type_in(styles) {
@@ -1207,6 +1234,10 @@ pub(crate) fn features(styles: StyleChain) -> Vec<Feature> {
         feat(b"frac", 1);
     }
 
+    if TextElem::ordinals_in(styles) {
+        feat(b"ordn", 1);
+    }
+
     for (tag, value) in TextElem::features_in(styles).0 {
         tags.push(Feature::new(tag, value, ..))
     }
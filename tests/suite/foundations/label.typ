@@ -69,6 +69,16 @@ _Visible_
 #test(str(label("hey")), "hey")
 #test(str([Hmm<hey>].label), "hey")
 
+--- label-query-after-layout ---
+// Test that a labelled element can be found after layout.
+#box(width: 10pt) <target>
+#context {
+  let found = query(<target>)
+  test(found.len(), 1)
+  test(found.first().func(), box)
+  test(found.first().width, 10pt)
+}
+
 --- label-in-code-mode-hint ---
 // Error: 7-7 expected semicolon or line break
 // Hint: 7-7 labels can only be applied in markup mode
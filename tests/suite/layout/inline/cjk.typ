@@ -70,6 +70,11 @@
 
 参看1378页〖象形文字〗。（《现代汉语词典》修订本）
 
+--- cjk-no-break-before-closing-bracket ---
+// A Japanese closing bracket never starts a line.
+#set text(lang: "ja")
+#context test(measure(box(width: 1em)[あ」]).height, measure[あ」].height)
+
 --- issue-2538-cjk-latin-spacing-before-linebreak ---
 // Issue #2538
 #set text(cjk-latin-spacing: auto)
@@ -17,6 +17,13 @@ There are non\u{2011}breaking~characters.
 #show "_": sym.space.nobreak.narrow
 0.1_g, 1_g, 10_g, 100_g, 1_000_g, 10_000_g, 100_000_g, 1_000_000_g
 
+--- linebreak-nbsp-consecutive ---
+// Consecutive non-breaking spaces are all kept and never break.
+#context {
+  test(measure[a~~~b].width > measure[a~b].width, true)
+  test(measure(box(width: 1pt)[a~~~b]).height, measure[a~~~b].height)
+}
+
 --- linebreak-shape-run ---
 // Test that there are no unwanted line break opportunities on run change.
 This is partly emp#emph[has]ized.
@@ -24,6 +31,14 @@ This is partly emp#emph[has]ized.
 --- linebreak-manual ---
 Hard #linebreak() break.
 
+--- linebreak-manual-spacing ---
+// A manual line break only adds leading, not paragraph spacing.
+#context {
+  let broken = measure[a #linebreak() b].height
+  assert(broken > measure[a].height)
+  assert(broken < measure[a #parbreak() b].height)
+}
+
 --- linebreak-manual-directly-after-automatic ---
 // Test hard break directly after normal break.
 Hard break directly after \ normal break.
@@ -5,6 +5,10 @@
 #text(kerning: true)[Tq] \
 #text(kerning: false)[Tq]
 
+--- text-kerning-width ---
+// Kerning pulls the "V" closer to the "A".
+#context test(measure(text(kerning: false)[AV]).width > measure[AV].width, true)
+
 --- text-alternates-and-stylistic-sets ---
 // Test alternates and stylistic sets.
 #set text(font: "IBM Plex Serif")
@@ -15,6 +19,20 @@ a vs #text(alternates: true)[a] \
 // Test ligatures.
 fi vs. #text(ligatures: false)[No fi]
 
+--- text-ligatures-historical-and-discretionary ---
+// Test that the ligature properties enable the right features.
+#let historical = text(historical-ligatures: true)[ſt ct st]
+#let discretionary = text(discretionary-ligatures: true)[ſt ct st]
+#context test(
+  measure(historical).width,
+  measure(text(features: ("hlig",))[ſt ct st]).width,
+)
+#context test(
+  measure(discretionary).width,
+  measure(text(features: ("dlig",))[ſt ct st]).width,
+)
+#context test(measure(historical).width != measure[ſt ct st].width, true)
+
 --- text-number-type ---
 // Test number type.
 #set text(number-type: "old-style")
@@ -27,17 +45,44 @@ fi vs. #text(ligatures: false)[No fi]
 #text(number-width: "tabular")[3456789123] \
 #text(number-width: "tabular")[0123456789]
 
+--- text-number-width-tabular-advances ---
+// Tabular figures all have the same advance.
+#set text(number-width: "tabular")
+#context test(measure[1].width, measure[8].width)
+
 --- text-slashed-zero-and-fractions ---
 // Test extra number stuff.
 #set text(font: "IBM Plex Serif")
 0 vs. #text(slashed-zero: true)[0] \
 1/2 vs. #text(fractions: true)[1/2]
 
+--- text-ordinals ---
+// Test that ordinals enable the `ordn` feature and that it has an effect.
+#set text(font: "IBM Plex Serif")
+#context test(
+  measure(text(ordinals: true)[1st 2a]).width,
+  measure(text(features: ("ordn",))[1st 2a]).width,
+)
+#context test(
+  measure(text(ordinals: true)[1st 2a]).width != measure[1st 2a].width,
+  true,
+)
+
 --- text-features ---
 // Test raw features.
 #text(features: ("smcp",))[Smcp] \
 fi vs. #text(features: (liga: 0))[No fi]
 
+--- text-features-arabic-isolated ---
+// Disabling the positional features forces isolated Arabic letter forms,
+// which are wider than the joined ones.
+#set text(font: "Noto Sans Arabic")
+#context {
+  let joined = measure[ببب].width
+  let isolated = measure(text(features: (init: 0, medi: 0, fina: 0))[ببب]).width
+  assert(isolated > joined)
+}
+
 --- text-stylistic-set-bad-type ---
 // Error: 26-31 expected integer or none, found boolean
 #set text(stylistic-set: false)
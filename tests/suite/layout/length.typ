@@ -49,6 +49,10 @@
 // Hint: 17-19 a length needs a unit - did you mean 12pt?
 #set text(size: 12)
 
+--- length-unit-invalid ---
+// Error: 17-21 invalid number suffix: px
+#set text(size: 12px)
+
 --- length-ignore-em-pt-hint ---
 // Error: 2-21 cannot convert a length with non-zero em units (`-6pt + 10.5em`) to pt
 // Hint: 2-21 use `length.abs.pt()` instead to ignore its em component
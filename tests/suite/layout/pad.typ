@@ -14,6 +14,15 @@
 
 Hi #box(pad(left: 10pt)[A]) there
 
+--- pad-measure ---
+// The padding adds to the size of the body.
+#context {
+  let body = box(width: 20pt, height: 10pt)
+  let size = measure(pad(left: 4pt, right: 6pt, top: 2pt, body))
+  test(size.width, 30pt)
+  test(size.height, 12pt)
+}
+
 --- pad-expanding-contents ---
 // Pad can grow.
 #pad(left: 10pt, right: 10pt)[PL #h(1fr) PR]
@@ -58,6 +58,14 @@ nor #xetex!
   one(angle * 1deg)
 }
 
+--- transform-rotate-reflow-measure ---
+// Test that a reflowing quarter rotation swaps width and height.
+#context {
+  let size = measure(rotate(90deg, reflow: true, box(width: 20pt, height: 10pt)))
+  test(calc.round(size.width.pt(), digits: 6), 10.0)
+  test(calc.round(size.height.pt(), digits: 6), 20.0)
+}
+
 --- transform-rotate-relative-sizing ---
 // Test relative sizing in rotated boxes.
 #set page(width: 200pt, height: 200pt)
@@ -63,6 +63,13 @@ Three
 // Test parenthesised assignments.
 #let (a) = (1, 2)
 
+--- let-unknown-similar ---
+// Test that a misspelled variable suggests a similarly named one.
+#let greeting = "Hello"
+// Error: 2-10 unknown variable: greating
+// Hint: 2-10 did you mean `greeting`?
+#greating
+
 --- let-incomplete ---
 // Error: 5 expected pattern
 #let
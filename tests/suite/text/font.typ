@@ -41,6 +41,13 @@ Emoji: 🐪, 🌋, 🏞
 #set text(font: ("PT Sans", "Twitter Color Emoji"), fallback: false)
 2π = 𝛼 + 𝛽. ✅
 
+--- text-font-weight-numeric ---
+// A numeric weight selects the closest available face.
+#context test(
+  measure(text(weight: 300)[Hello]).width < measure(text(weight: 700)[Hello]).width,
+  true,
+)
+
 --- text-call-body ---
 // Test string body.
 #text("Text") \
@@ -10,3 +10,8 @@
 
 #show smallcaps: set text(fill: red)
 #smallcaps[Smallcaps]
+
+--- smallcaps-all ---
+// Test that uppercase letters become small capitals as well.
+#context test(measure(smallcaps(all: true)[ABC]).width < measure[ABC].width, true)
+#context test(measure(smallcaps[ABC]).width, measure[ABC].width)
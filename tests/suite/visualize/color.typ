@@ -185,6 +185,14 @@
 // Test gray color conversion.
 #stack(dir: ltr, rect(fill: luma(0)), rect(fill: luma(80%)))
 
+--- color-rgb-hex ---
+// Test that hex strings of all lengths agree with components.
+#test(rgb("#ff0000"), rgb(255, 0, 0))
+#test(rgb("#f00"), rgb(255, 0, 0))
+#test(rgb("f00"), rgb(255, 0, 0))
+#test(rgb("#ff000080"), rgb(255, 0, 0, 128))
+#test(rgb("#f008"), rgb(255, 0, 0, 136))
+
 --- color-rgb-out-of-range ---
 // Error for values that are out of range.
 // Error: 11-14 number must be between 0 and 255
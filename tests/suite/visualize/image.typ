@@ -45,6 +45,14 @@ Stuff
 // Test baseline.
 A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
 
+--- image-inline-line-height ---
+// Test that an inline image sitting on the baseline grows its line.
+#set text(10pt)
+#context {
+  let size = measure[A #box(image("/assets/images/tiger.jpg", height: 2em)) B]
+  test(size.height, 20pt)
+}
+
 --- image-svg-complex ---
 // Test advanced SVG features.
 #image("/assets/images/pattern.svg")
@@ -11,6 +11,14 @@
 
 #line(end: (70%, 50%))
 
+--- line-measure ---
+// The size of a line is given by its geometry, not by its stroke.
+#context {
+  let size = measure(line(length: 2cm, stroke: 3pt))
+  test(size.width, 2cm)
+  test(size.height, 0pt)
+}
+
 --- line-positioning ---
 // Test the angle argument and positioning.
 